@@ -0,0 +1,259 @@
+use std::str;
+
+fn scalar_decode(encoded: &[u8], digit_count: usize) -> String {
+    let mut digits = String::with_capacity(encoded.len() * 2);
+    for &b in encoded {
+        let high = (b >> 4) & 0x0F;
+        let low  = b & 0x0F;
+        // from_digit always returns Some for 0–9
+        digits.push(char::from_digit(high as u32, 10).unwrap());
+        digits.push(char::from_digit(low  as u32, 10).unwrap());
+    }
+    digits.chars().take(digit_count).collect()
+}
+
+fn scalar_encode(digits: &str) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(digits.len().div_ceil(2));
+    let mut iter = digits.chars().map(|c| c.to_digit(10).unwrap() as u8);
+
+    while let Some(high) = iter.next() {
+        // Use 0xF for padding if odd number of digits - adjust if spec requires different padding
+        let low = iter.next().unwrap_or(0x0F);
+        encoded.push((high << 4) | low);
+    }
+    encoded
+}
+
+#[cfg(not(feature = "simd"))]
+pub fn decode_pack_bcd(encoded: &[u8], digit_count: usize) -> String {
+    scalar_decode(encoded, digit_count)
+}
+
+#[cfg(not(feature = "simd"))]
+pub fn encode_pack_bcd(digits: &str) -> Vec<u8> {
+    scalar_encode(digits)
+}
+
+/// Vectorized packed-BCD codec for bulk feeds, enabled with the `simd`
+/// feature. Processes 16 input bytes (32 digits) per iteration on
+/// `std::simd` and falls back to the scalar codec above for whatever
+/// doesn't divide evenly into a full lane, so outputs stay byte-identical
+/// to the scalar path regardless of feature selection.
+#[cfg(feature = "simd")]
+pub use simd::{decode_pack_bcd, encode_pack_bcd};
+
+#[cfg(feature = "simd")]
+mod simd {
+    use super::{scalar_decode, scalar_encode};
+    use std::simd::prelude::*;
+    use std::simd::simd_swizzle;
+
+    const LANES: usize = 16;
+
+    // Interleaves two 16-lane vectors `a`/`b` lane-by-lane into a 32-lane
+    // vector: [a0, b0, a1, b1, ..., a15, b15].
+    const INTERLEAVE_IDX: [usize; 32] = {
+        let mut idx = [0usize; 32];
+        let mut i = 0;
+        while i < LANES {
+            idx[i * 2] = i;
+            idx[i * 2 + 1] = i + LANES;
+            i += 1;
+        }
+        idx
+    };
+    // Picks every even / odd lane out of a 32-lane vector, i.e. the
+    // inverse of `INTERLEAVE_IDX`.
+    const EVEN_IDX: [usize; LANES] = {
+        let mut idx = [0usize; LANES];
+        let mut i = 0;
+        while i < LANES {
+            idx[i] = i * 2;
+            i += 1;
+        }
+        idx
+    };
+    const ODD_IDX: [usize; LANES] = {
+        let mut idx = [0usize; LANES];
+        let mut i = 0;
+        while i < LANES {
+            idx[i] = i * 2 + 1;
+            i += 1;
+        }
+        idx
+    };
+
+    pub fn decode_pack_bcd(encoded: &[u8], digit_count: usize) -> String {
+        let mut digits = String::with_capacity(encoded.len() * 2);
+        let mut chunks = encoded.chunks_exact(LANES);
+        for chunk in &mut chunks {
+            let v = u8x16::from_slice(chunk);
+            let ascii = u8x16::splat(0x30);
+            let high = ((v >> 4) & u8x16::splat(0x0F)) + ascii;
+            let low = (v & u8x16::splat(0x0F)) + ascii;
+            let interleaved: u8x32 = simd_swizzle!(high, low, INTERLEAVE_IDX);
+            digits.push_str(str::from_utf8(&interleaved.to_array()).expect("BCD digits are ASCII"));
+        }
+        let tail = chunks.remainder();
+        digits.push_str(&scalar_decode(tail, tail.len() * 2));
+        digits.chars().take(digit_count).collect()
+    }
+
+    pub fn encode_pack_bcd(digits: &str) -> Vec<u8> {
+        let bytes = digits.as_bytes();
+        let mut encoded = Vec::with_capacity(bytes.len().div_ceil(2));
+        let mut chunks = bytes.chunks_exact(LANES * 2);
+        for chunk in &mut chunks {
+            let v = u8x32::from_slice(chunk);
+            let nibbles = v - u8x32::splat(0x30);
+            let high: u8x16 = simd_swizzle!(nibbles, EVEN_IDX);
+            let low: u8x16 = simd_swizzle!(nibbles, ODD_IDX);
+            encoded.extend_from_slice(&((high << 4) | low).to_array());
+        }
+        let tail = str::from_utf8(chunks.remainder()).expect("BCD digits are ASCII");
+        encoded.extend(scalar_encode(tail));
+        encoded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_basic_decoding() {
+        let cases = vec![
+            (vec![0x12, 0x34], 4, "1234"),
+            (vec![0x00, 0x01], 4, "0001"),
+            (vec![0x98, 0x76], 4, "9876"),
+            (vec![0x12, 0x34, 0x56], 5, "12345"),
+        ];
+        for (encoded, digits, expected) in cases {
+            let result = decode_pack_bcd(&encoded, digits);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_decimal_insertion() {
+        let encoded = vec![0x12, 0x34, 0x56]; // "123456"
+        let raw = decode_pack_bcd(&encoded, 6);
+        assert_eq!(raw, "123456");
+
+        // Insert decimal point after 3 digits
+        let decimal_str = format!("{}.{}", &raw[..3], &raw[3..]);
+        assert_eq!(decimal_str, "123.456");
+
+        // Parse to Decimal for precise check
+        let dec = Decimal::from_str(&decimal_str).unwrap();
+        assert_eq!(dec.to_string(), "123.456");
+    }
+
+    #[test]
+    fn test_encode_pack_bcd() {
+        let cases = vec![
+            ("1234", vec![0x12, 0x34]),
+            ("0001", vec![0x00, 0x01]),
+            ("9876", vec![0x98, 0x76]),
+            ("12345", vec![0x12, 0x34, 0x5F]), // Assuming 0xF padding for odd length
+            ("", vec![]),
+        ];
+        for (digits, expected) in cases {
+            let result = encode_pack_bcd(digits);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_encoding_performance() {
+        let num_digits = 100000; // Number of digits for testing
+        let iterations = 1000; // Number of iterations for averaging
+
+        // Generate a long string of digits
+        let mut digits_str = String::with_capacity(num_digits);
+        for i in 0..num_digits {
+            digits_str.push(char::from_digit((i % 10) as u32, 10).unwrap());
+        }
+
+        // --- BCD Performance ---
+        let mut total_bcd_time = std::time::Duration::new(0, 0);
+        // Run once outside loop to ensure correctness (optional)
+        let bcd_encoded_check = encode_pack_bcd(&digits_str);
+        let bcd_decoded_check = decode_pack_bcd(&bcd_encoded_check, digits_str.len());
+        assert_eq!(bcd_decoded_check, digits_str);
+
+        for _ in 0..iterations {
+            let start = std::time::Instant::now();
+            let bcd_encoded = encode_pack_bcd(&digits_str);
+            let _bcd_decoded = decode_pack_bcd(&bcd_encoded, digits_str.len());
+            total_bcd_time += start.elapsed();
+        }
+        let avg_bcd_time = total_bcd_time / iterations as u32;
+
+        // --- ASCII Performance ---
+        let mut total_ascii_time = std::time::Duration::new(0, 0);
+        // ASCII encoding is essentially getting the bytes
+        let ascii_encoded_check = digits_str.as_bytes();
+        // ASCII decoding is converting bytes back to string
+        let ascii_decoded_check = str::from_utf8(ascii_encoded_check).unwrap();
+         assert_eq!(ascii_decoded_check, digits_str);
+
+        for _ in 0..iterations {
+             let start = std::time::Instant::now();
+             // Simulate ASCII "encoding" (getting bytes)
+             let ascii_encoded = digits_str.as_bytes();
+             // Simulate ASCII "decoding" (creating String from bytes)
+             let _ascii_decoded = str::from_utf8(ascii_encoded).unwrap(); // Using unwrap for simplicity in benchmark
+             total_ascii_time += start.elapsed();
+        }
+         let avg_ascii_time = total_ascii_time / iterations as u32;
+
+
+        println!("
+--- Encoding/Decoding Performance Comparison ---");
+        println!("Digits: {}", num_digits);
+        println!("Iterations: {}", iterations);
+        println!("Average Packed BCD time: {:?}", avg_bcd_time);
+        println!("Average ASCII time:      {:?}", avg_ascii_time);
+
+        // You might want to add assertions comparing times,
+        // but performance can vary greatly depending on hardware and optimizations.
+        // e.g., assert!(avg_bcd_time < avg_ascii_time * 2, "BCD should generally be faster or comparable for pure encode/decode");
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_simd_decode_matches_scalar_across_tail_lengths() {
+        const LANE_BYTES: usize = 16;
+        // Exercise every possible tail length (0..16) around a full SIMD
+        // lane so the scalar fallback path is covered alongside the
+        // vectorized one.
+        for extra_bytes in 0..LANE_BYTES {
+            let total_bytes = LANE_BYTES + extra_bytes;
+            let encoded: Vec<u8> = (0..total_bytes).map(|i| ((i % 10) << 4 | ((i + 1) % 10)) as u8).collect();
+            let digit_count = total_bytes * 2;
+
+            let simd_result = simd::decode_pack_bcd(&encoded, digit_count);
+            let scalar_result = scalar_decode(&encoded, digit_count);
+            assert_eq!(simd_result, scalar_result);
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_simd_encode_matches_scalar_including_odd_length_padding() {
+        const LANE_DIGITS: usize = 32;
+        for extra_digits in 0..LANE_DIGITS {
+            let total_digits = LANE_DIGITS + extra_digits;
+            let digits: String = (0..total_digits)
+                .map(|i| char::from_digit((i % 10) as u32, 10).unwrap())
+                .collect();
+
+            let simd_result = simd::encode_pack_bcd(&digits);
+            let scalar_result = scalar_encode(&digits);
+            assert_eq!(simd_result, scalar_result);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Small parser-combinator primitives for decoding Format 6 records.
+//!
+//! Each combinator takes the remaining input plus the absolute offset of
+//! its first byte (for error reporting) and returns `Result<(&[u8], T),
+//! ParseError>`, threading the unconsumed input back to the caller in the
+//! style of nom's byte parsers. None of these ever index or unwrap their
+//! way into a panic on malformed input.
+
+use crate::error::ParseError;
+
+fn require(input: &[u8], n: usize, offset: usize) -> Result<(), ParseError> {
+    if input.len() < n {
+        Err(ParseError::Truncated {
+            offset,
+            needed: n,
+            available: input.len(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Consumes a single raw byte.
+pub fn take_u8(input: &[u8], offset: usize) -> Result<(&[u8], u8), ParseError> {
+    require(input, 1, offset)?;
+    Ok((&input[1..], input[0]))
+}
+
+/// Consumes `n` raw bytes.
+pub fn take_bytes(input: &[u8], n: usize, offset: usize) -> Result<(&[u8], &[u8]), ParseError> {
+    require(input, n, offset)?;
+    Ok((&input[n..], &input[..n]))
+}
+
+/// Consumes `n` bytes and interprets them as a UTF-8 string.
+pub fn take_ascii(input: &[u8], n: usize, offset: usize) -> Result<(&[u8], String), ParseError> {
+    let (rest, bytes) = take_bytes(input, n, offset)?;
+    let s = std::str::from_utf8(bytes).map_err(|_| ParseError::InvalidUtf8 { offset })?;
+    Ok((rest, s.to_string()))
+}
+
+/// Consumes `n_bytes` of packed BCD and decodes the first `digit_count`
+/// nibbles into a digit string, validating each nibble is in 0-9. Nibbles
+/// beyond `digit_count` (i.e. odd-length padding) are consumed but not
+/// validated, since they are never meant to hold a digit.
+pub fn take_bcd(
+    input: &[u8],
+    n_bytes: usize,
+    digit_count: usize,
+    offset: usize,
+) -> Result<(&[u8], String), ParseError> {
+    let (rest, bytes) = take_bytes(input, n_bytes, offset)?;
+    let mut digits = String::with_capacity(digit_count);
+    for (i, &b) in bytes.iter().enumerate() {
+        for nibble in [(b >> 4) & 0x0F, b & 0x0F] {
+            if digits.len() == digit_count {
+                return Ok((rest, digits));
+            }
+            if nibble > 9 {
+                return Err(ParseError::InvalidBcdNibble {
+                    offset: offset + i,
+                    nibble,
+                });
+            }
+            digits.push((b'0' + nibble) as char);
+        }
+    }
+    Ok((rest, digits))
+}
+
+/// Parses a digit string already validated by [`take_bcd`] into a `u32`.
+///
+/// Every byte is known to be an ASCII digit and the widest field parsed
+/// through here (the 8-digit accumulative volume) fits comfortably in a
+/// `u32`, so this cannot fail.
+pub fn bcd_digits_to_u32(digits: &str) -> u32 {
+    digits.bytes().fold(0u32, |acc, b| acc * 10 + (b - b'0') as u32)
+}
@@ -0,0 +1,241 @@
+use crate::combinators::{bcd_digits_to_u32, take_bcd};
+use crate::error::ParseError;
+use crate::format6::{parse_format6_checked_framed, Format6Record};
+
+const ESC: u8 = 0x1B;
+const TERMINAL: [u8; 2] = [0x0D, 0x0A];
+
+/// Decodes a stream of Format 6 records out of a rolling byte buffer, so
+/// callers can feed raw socket reads straight in instead of pre-slicing
+/// individual records.
+///
+/// Frame boundaries are estimated from the BCD `info_length` header right
+/// after the ESC marker; if that header isn't buffered yet, or the
+/// variable quote count makes its declared length unreliable, the decoder
+/// falls back to scanning for the `0x0D 0x0A` terminal. Either way, the
+/// estimate only gates *when* a full parse is attempted — [`parse_format6`](crate::parse_format6)
+/// itself remains the source of truth for where the record actually ends.
+/// A record that fails to parse or fails its checksum resynchronizes by
+/// discarding bytes up to the next ESC marker.
+pub struct Format6Decoder {
+    buffer: Vec<u8>,
+}
+
+impl Format6Decoder {
+    pub fn new() -> Self {
+        Self { buffer: Vec::new() }
+    }
+
+    /// Appends newly-received bytes (e.g. from a socket read) to the
+    /// decoder's internal rolling buffer.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+}
+
+impl Default for Format6Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Estimates the total frame length from the ESC-prefixed `info_length`
+/// BCD header, if enough bytes are buffered to read it.
+fn declared_frame_len(buffer: &[u8]) -> Option<usize> {
+    if buffer.len() < 3 {
+        return None;
+    }
+    let (_, digits) = take_bcd(&buffer[1..3], 2, 4, 1).ok()?;
+    Some(3 + bcd_digits_to_u32(&digits) as usize)
+}
+
+/// Looks for the `0x0D 0x0A` terminal after the header, returning the
+/// length of the buffer prefix that ends with it.
+fn terminal_frame_len(buffer: &[u8]) -> Option<usize> {
+    if buffer.len() < 3 {
+        return None;
+    }
+    buffer[3..]
+        .windows(TERMINAL.len())
+        .position(|w| w == TERMINAL)
+        .map(|idx| 3 + idx + TERMINAL.len())
+}
+
+impl Iterator for Format6Decoder {
+    type Item = Result<Format6Record, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let esc_pos = self.buffer.iter().position(|&b| b == ESC)?;
+        if esc_pos > 0 {
+            self.buffer.drain(..esc_pos);
+        }
+
+        let frame_len = declared_frame_len(&self.buffer)
+            .filter(|&len| len <= self.buffer.len())
+            .or_else(|| terminal_frame_len(&self.buffer))?;
+
+        let result = match parse_format6_checked_framed(&self.buffer[..frame_len]) {
+            // The declared length undershot the true frame (e.g. a stale
+            // `info_length` that wasn't recomputed for the quote count);
+            // retry against the terminal-scan estimate before giving up.
+            Err(truncated @ ParseError::Truncated { .. }) => {
+                match terminal_frame_len(&self.buffer).filter(|&len| len > frame_len) {
+                    Some(retry_len) => parse_format6_checked_framed(&self.buffer[..retry_len]),
+                    None => Err(truncated),
+                }
+            }
+            other => other,
+        };
+
+        match result {
+            Ok((record, consumed)) => {
+                self.buffer.drain(..consumed);
+                Some(Ok(record))
+            }
+            Err(ParseError::Truncated { .. }) => {
+                // The estimate was optimistic; wait for more bytes rather
+                // than treating this as corruption.
+                None
+            }
+            Err(err) => {
+                match self.buffer[1..].iter().position(|&b| b == ESC) {
+                    Some(next_esc) => {
+                        self.buffer.drain(..=next_esc);
+                    }
+                    None => self.buffer.clear(),
+                }
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format6::RealTimeQuote;
+    use crate::{encode_format6, Format6Record};
+
+    fn sample_record() -> Format6Record {
+        Format6Record {
+            esc_code: ESC,
+            info_length: 47,
+            business_type: "01".to_string(),
+            format_code: "06".to_string(),
+            version: "04".to_string(),
+            transmission_sn: "00000001".to_string(),
+            stock_code: "2330".to_string(),
+            matching_time: "093015123456".to_string(),
+            disclosed_item_remarks: 0x10,
+            rise_fall_remarks: 0x00,
+            status_remarks: 0x80,
+            accumulative_volume: 1234,
+            bid_count: 1,
+            ask_count: 0,
+            real_time_quotes: vec![RealTimeQuote {
+                price: "001234567".to_string(),
+                volume: "00000100".to_string(),
+            }],
+            price_scale: crate::format6::EQUITY_PRICE_SCALE,
+            checksum: 0, // encode_format6 recomputes this
+            terminal_code: TERMINAL,
+        }
+    }
+
+    #[test]
+    fn test_decoder_yields_a_record_pushed_in_one_go() {
+        let bytes = encode_format6(&sample_record()).unwrap();
+        let mut decoder = Format6Decoder::new();
+        decoder.push(&bytes);
+
+        let rec = decoder.next().unwrap().unwrap();
+        assert_eq!(rec.stock_code, "2330");
+        assert!(decoder.next().is_none());
+    }
+
+    #[test]
+    fn test_decoder_waits_for_a_record_split_across_pushes() {
+        let bytes = encode_format6(&sample_record()).unwrap();
+        let (first_half, second_half) = bytes.split_at(bytes.len() / 2);
+
+        let mut decoder = Format6Decoder::new();
+        decoder.push(first_half);
+        assert!(decoder.next().is_none());
+
+        decoder.push(second_half);
+        let rec = decoder.next().unwrap().unwrap();
+        assert_eq!(rec.stock_code, "2330");
+    }
+
+    #[test]
+    fn test_decoder_decodes_back_to_back_records() {
+        let mut bytes = encode_format6(&sample_record()).unwrap();
+        bytes.extend(encode_format6(&sample_record()).unwrap());
+
+        let mut decoder = Format6Decoder::new();
+        decoder.push(&bytes);
+
+        assert!(decoder.next().unwrap().unwrap().stock_code == "2330");
+        assert!(decoder.next().unwrap().unwrap().stock_code == "2330");
+        assert!(decoder.next().is_none());
+    }
+
+    #[test]
+    fn test_decoder_skips_junk_before_the_first_esc() {
+        let mut bytes = vec![0xFF, 0xEE, 0x00];
+        bytes.extend(encode_format6(&sample_record()).unwrap());
+
+        let mut decoder = Format6Decoder::new();
+        decoder.push(&bytes);
+
+        let rec = decoder.next().unwrap().unwrap();
+        assert_eq!(rec.stock_code, "2330");
+    }
+
+    #[test]
+    fn test_decoder_yields_a_record_whose_declared_length_undershoots_its_quotes() {
+        // info_length is left at the stale 1-quote value even though
+        // bid_count/ask_count now describe 3 quotes, so the
+        // declared-length estimate is too short for the real frame.
+        let record = Format6Record {
+            disclosed_item_remarks: 0x12, // bid_count = 1, ask_count = 2
+            bid_count: 1,
+            ask_count: 2,
+            real_time_quotes: vec![
+                RealTimeQuote { price: "001234567".to_string(), volume: "00000100".to_string() },
+                RealTimeQuote { price: "001234568".to_string(), volume: "00000200".to_string() },
+                RealTimeQuote { price: "001234569".to_string(), volume: "00000300".to_string() },
+            ],
+            ..sample_record()
+        };
+        let bytes = encode_format6(&record).unwrap();
+        assert!(declared_frame_len(&bytes).unwrap() < bytes.len());
+
+        let mut decoder = Format6Decoder::new();
+        decoder.push(&bytes);
+
+        let rec = decoder.next().unwrap().unwrap();
+        assert_eq!(rec.real_time_quotes.len(), 3);
+        assert!(decoder.next().is_none());
+    }
+
+    #[test]
+    fn test_decoder_resyncs_past_a_corrupt_frame() {
+        let mut corrupt = encode_format6(&sample_record()).unwrap();
+        // Flip the checksum so the first frame fails verification.
+        let checksum_index = corrupt.len() - 3;
+        corrupt[checksum_index] ^= 0xFF;
+
+        let mut bytes = corrupt;
+        bytes.extend(encode_format6(&sample_record()).unwrap());
+
+        let mut decoder = Format6Decoder::new();
+        decoder.push(&bytes);
+
+        let first = decoder.next().unwrap();
+        assert!(matches!(first, Err(ParseError::ChecksumMismatch { .. })));
+
+        let second = decoder.next().unwrap().unwrap();
+        assert_eq!(second.stock_code, "2330");
+    }
+}
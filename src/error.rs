@@ -0,0 +1,94 @@
+use std::fmt;
+
+/// Errors produced while decoding a Format 6 record from raw wire bytes.
+///
+/// Every variant carries the byte offset within the original input where
+/// the problem was detected, so callers get an actionable diagnostic
+/// instead of a panic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Not enough bytes remained in the input to satisfy the next field.
+    Truncated {
+        offset: usize,
+        needed: usize,
+        available: usize,
+    },
+    /// A BCD nibble fell outside the 0-9 digit range (0xA-0xF).
+    InvalidBcdNibble { offset: usize, nibble: u8 },
+    /// A field expected to be ASCII/UTF-8 (e.g. the stock code) was not.
+    InvalidUtf8 { offset: usize },
+    /// The stored checksum byte didn't match `compute_checksum` of the
+    /// bytes preceding it.
+    ChecksumMismatch {
+        offset: usize,
+        expected: u8,
+        found: u8,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Truncated {
+                offset,
+                needed,
+                available,
+            } => write!(
+                f,
+                "truncated input at offset {offset}: needed {needed} byte(s), only {available} available"
+            ),
+            ParseError::InvalidBcdNibble { offset, nibble } => {
+                write!(f, "invalid BCD nibble 0x{nibble:X} at offset {offset}")
+            }
+            ParseError::InvalidUtf8 { offset } => {
+                write!(f, "invalid UTF-8 at offset {offset}")
+            }
+            ParseError::ChecksumMismatch {
+                offset,
+                expected,
+                found,
+            } => write!(
+                f,
+                "checksum mismatch at offset {offset}: expected 0x{expected:02X}, found 0x{found:02X}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Errors produced while serializing a [`crate::Format6Record`] back into
+/// wire bytes with [`crate::encode_format6`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// A field that's encoded as packed BCD contains a character that
+    /// isn't an ASCII digit.
+    NonDigitField { field: &'static str, found: char },
+    /// A numeric field's value doesn't fit the wire format's fixed digit
+    /// width for it.
+    FieldTooWide {
+        field: &'static str,
+        max_digits: u32,
+        value: u32,
+    },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::NonDigitField { field, found } => {
+                write!(f, "field {field} contains non-digit character {found:?}")
+            }
+            EncodeError::FieldTooWide {
+                field,
+                max_digits,
+                value,
+            } => write!(
+                f,
+                "field {field} value {value} does not fit in {max_digits} digit(s)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
@@ -0,0 +1,542 @@
+use rust_decimal::Decimal;
+
+use crate::bcd::encode_pack_bcd;
+use crate::combinators::{bcd_digits_to_u32, take_ascii, take_bcd, take_bytes, take_u8};
+use crate::error::{EncodeError, ParseError};
+
+/// Implied decimal places for TWSE equity prices, used as
+/// `Format6Record::price_scale`'s default. Futures/indices instruments
+/// with a different fixed-point convention can override it per record.
+pub const EQUITY_PRICE_SCALE: u32 = 3;
+
+#[derive(Debug, PartialEq)]
+pub struct RealTimeQuote {
+    pub price:  String,
+    pub volume: String,
+}
+
+impl RealTimeQuote {
+    /// Interprets `price` as a fixed-point [`Decimal`] with `scale`
+    /// implied decimal places, e.g. `"001234567"` with `scale = 3`
+    /// becomes `1234.567`. Built from the digit string's integer value
+    /// via `Decimal::new`, never by splitting or reformatting the string.
+    pub fn price_decimal(&self, scale: u32) -> Decimal {
+        digits_to_decimal(&self.price, scale)
+    }
+
+    /// Interprets `volume` as a whole-share count, wrapped in [`Decimal`]
+    /// for uniform downstream P&L math.
+    pub fn volume_decimal(&self) -> Decimal {
+        digits_to_decimal(&self.volume, 0)
+    }
+}
+
+fn digits_to_decimal(digits: &str, scale: u32) -> Decimal {
+    let mantissa: i64 = digits
+        .parse()
+        .expect("BCD digit strings decoded by take_bcd are always numeric");
+    Decimal::new(mantissa, scale)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Format6Record {
+    pub esc_code:               u8,
+    pub info_length:            u32,
+    pub business_type:          String,
+    pub format_code:            String,
+    pub version:                String,
+    pub transmission_sn:        String,
+    pub stock_code:             String,
+    pub matching_time:          String,
+    pub disclosed_item_remarks: u8,
+    pub rise_fall_remarks:      u8,
+    pub status_remarks:         u8,
+    pub accumulative_volume:    u32,
+    /// Number of bid-side entries at the front of `real_time_quotes`,
+    /// decoded from the high nibble of `disclosed_item_remarks`.
+    pub bid_count:              u8,
+    /// Number of ask-side entries following the bids in
+    /// `real_time_quotes`, decoded from its low nibble.
+    pub ask_count:              u8,
+    pub real_time_quotes:       Vec<RealTimeQuote>,
+    /// Implied decimal places for `real_time_quotes` prices. Defaults to
+    /// [`EQUITY_PRICE_SCALE`]; set this after parsing for instruments
+    /// (futures, indices) with a different fixed-point convention.
+    pub price_scale:            u32,
+    pub checksum:               u8,
+    pub terminal_code:          [u8; 2],
+}
+
+impl Format6Record {
+    /// [`RealTimeQuote::price_decimal`] for every entry in
+    /// `real_time_quotes`, in the same bid-then-ask order.
+    pub fn quote_prices_decimal(&self) -> Vec<Decimal> {
+        self.real_time_quotes
+            .iter()
+            .map(|q| q.price_decimal(self.price_scale))
+            .collect()
+    }
+
+    /// [`RealTimeQuote::volume_decimal`] for every entry in
+    /// `real_time_quotes`, in the same bid-then-ask order.
+    pub fn quote_volumes_decimal(&self) -> Vec<Decimal> {
+        self.real_time_quotes.iter().map(|q| q.volume_decimal()).collect()
+    }
+}
+
+/// Parses a single Format 6 record from `raw`, returning a [`ParseError`]
+/// instead of panicking on truncated input, an invalid BCD nibble, or
+/// non-UTF-8 bytes in the stock code.
+pub fn parse_format6(raw: &[u8]) -> Result<Format6Record, ParseError> {
+    let (record, _checksum_offset) = parse_format6_with_checksum_offset(raw)?;
+    Ok(record)
+}
+
+/// Like [`parse_format6`], but also verifies the stored `checksum` against
+/// `compute_checksum` of the bytes that precede it and fails with
+/// [`ParseError::ChecksumMismatch`] if they disagree.
+pub fn parse_format6_checked(raw: &[u8]) -> Result<Format6Record, ParseError> {
+    let (record, checksum_offset) = parse_format6_with_checksum_offset(raw)?;
+    let expected = compute_checksum(&raw[..checksum_offset]);
+    if expected != record.checksum {
+        return Err(ParseError::ChecksumMismatch {
+            offset: checksum_offset,
+            expected,
+            found: record.checksum,
+        });
+    }
+    Ok(record)
+}
+
+/// Like [`parse_format6_checked`], but also returns the total number of
+/// bytes of `raw` the record occupies (through its terminal code), so a
+/// framing layer can know exactly how much to consume. Used by
+/// [`crate::decoder::Format6Decoder`].
+pub(crate) fn parse_format6_checked_framed(raw: &[u8]) -> Result<(Format6Record, usize), ParseError> {
+    let (record, checksum_offset) = parse_format6_with_checksum_offset(raw)?;
+    let expected = compute_checksum(&raw[..checksum_offset]);
+    if expected != record.checksum {
+        return Err(ParseError::ChecksumMismatch {
+            offset: checksum_offset,
+            expected,
+            found: record.checksum,
+        });
+    }
+    // checksum byte + 2-byte terminal code follow the checked region.
+    Ok((record, checksum_offset + 3))
+}
+
+/// Parses a record, also returning the offset of its checksum byte within
+/// `raw` so callers can recompute and verify the checksum.
+fn parse_format6_with_checksum_offset(raw: &[u8]) -> Result<(Format6Record, usize), ParseError> {
+    let input = raw;
+    let mut pos = 0usize;
+
+    // 1) ESC-CODE
+    let (input, esc_code) = take_u8(input, pos)?;
+    pos += 1;
+
+    // 2) HEADER
+    let (input, info_length_digits) = take_bcd(input, 2, 4, pos)?;
+    pos += 2;
+    let info_length = bcd_digits_to_u32(&info_length_digits);
+
+    let (input, business_type) = take_bcd(input, 1, 2, pos)?;
+    pos += 1;
+
+    let (input, format_code) = take_bcd(input, 1, 2, pos)?;
+    pos += 1;
+
+    let (input, version) = take_bcd(input, 1, 2, pos)?;
+    pos += 1;
+
+    let (input, transmission_sn) = take_bcd(input, 4, 8, pos)?;
+    pos += 4;
+
+    // 3) BODY
+    let (input, stock_code) = take_ascii(input, 6, pos)?;
+    let stock_code = stock_code.trim_end().to_string();
+    pos += 6;
+
+    let (input, matching_time) = take_bcd(input, 6, 12, pos)?;
+    pos += 6;
+
+    let (input, disclosed_item_remarks) = take_u8(input, pos)?;
+    pos += 1;
+    let (input, rise_fall_remarks) = take_u8(input, pos)?;
+    pos += 1;
+    let (input, status_remarks) = take_u8(input, pos)?;
+    pos += 1;
+
+    let (input, accumulative_volume_digits) = take_bcd(input, 4, 8, pos)?;
+    pos += 4;
+    let accumulative_volume = bcd_digits_to_u32(&accumulative_volume_digits);
+
+    // 3.7 Real-time Quotes: the disclosed-item-remarks byte's high nibble
+    // is the number of bid levels and its low nibble the number of ask
+    // levels, each encoded as a 5-byte BCD price / 4-byte BCD volume pair.
+    let bid_count = (disclosed_item_remarks >> 4) & 0x0F;
+    let ask_count = disclosed_item_remarks & 0x0F;
+    let quote_count = (bid_count + ask_count) as usize;
+
+    let mut input = input;
+    let mut real_time_quotes = Vec::with_capacity(quote_count);
+    for _ in 0..quote_count {
+        let (rest, price) = take_bcd(input, 5, 9, pos)?;
+        pos += 5;
+        let (rest, volume) = take_bcd(rest, 4, 8, pos)?;
+        pos += 4;
+        real_time_quotes.push(RealTimeQuote { price, volume });
+        input = rest;
+    }
+
+    // 4) Checksum
+    let checksum_offset = pos;
+    let (input, checksum) = take_u8(input, pos)?;
+    pos += 1;
+
+    // 5) Terminal Code
+    let (_input, terminal_bytes) = take_bytes(input, 2, pos)?;
+    let terminal_code = [terminal_bytes[0], terminal_bytes[1]];
+
+    Ok((
+        Format6Record {
+            esc_code,
+            info_length,
+            business_type,
+            format_code,
+            version,
+            transmission_sn,
+            stock_code,
+            matching_time,
+            disclosed_item_remarks,
+            rise_fall_remarks,
+            status_remarks,
+            accumulative_volume,
+            bid_count,
+            ask_count,
+            real_time_quotes,
+            price_scale: EQUITY_PRICE_SCALE,
+            checksum,
+            terminal_code,
+        },
+        checksum_offset,
+    ))
+}
+
+/// XORs every byte from the ESC code up to (but excluding) the checksum
+/// byte, matching how `checksum` is expected to have been computed on the
+/// wire.
+pub fn compute_checksum(bytes_before_checksum: &[u8]) -> u8 {
+    bytes_before_checksum.iter().fold(0u8, |acc, &b| acc ^ b)
+}
+
+/// Checks that `value` is made up only of ASCII digits, as required of
+/// every `String` field `encode_format6` hands to [`encode_pack_bcd`].
+fn check_digits(field: &'static str, value: &str) -> Result<(), EncodeError> {
+    match value.chars().find(|c| !c.is_ascii_digit()) {
+        Some(found) => Err(EncodeError::NonDigitField { field, found }),
+        None => Ok(()),
+    }
+}
+
+/// Checks that `value` fits in `max_digits` decimal digits, as required
+/// for it to round-trip through a fixed-width BCD field.
+fn check_width(field: &'static str, value: u32, max_digits: u32) -> Result<(), EncodeError> {
+    if value >= 10u32.pow(max_digits) {
+        return Err(EncodeError::FieldTooWide { field, max_digits, value });
+    }
+    Ok(())
+}
+
+/// Serializes `record` back into wire bytes: BCD fields via
+/// [`encode_pack_bcd`], the stock code padded to 6 ASCII bytes, a freshly
+/// computed checksum, and the `0x0D 0x0A` terminal. Fails with an
+/// [`EncodeError`] instead of panicking or emitting a corrupt frame if a
+/// `String` field holds a non-digit character or a numeric field doesn't
+/// fit its fixed wire width.
+pub fn encode_format6(record: &Format6Record) -> Result<Vec<u8>, EncodeError> {
+    check_width("info_length", record.info_length, 4)?;
+    check_width("accumulative_volume", record.accumulative_volume, 8)?;
+    check_digits("business_type", &record.business_type)?;
+    check_digits("format_code", &record.format_code)?;
+    check_digits("version", &record.version)?;
+    check_digits("transmission_sn", &record.transmission_sn)?;
+    check_digits("matching_time", &record.matching_time)?;
+    for quote in &record.real_time_quotes {
+        check_digits("real_time_quotes[].price", &quote.price)?;
+        check_digits("real_time_quotes[].volume", &quote.volume)?;
+    }
+
+    let mut out = Vec::new();
+    out.push(record.esc_code);
+
+    out.extend(encode_pack_bcd(&format!("{:04}", record.info_length)));
+    out.extend(encode_pack_bcd(&record.business_type));
+    out.extend(encode_pack_bcd(&record.format_code));
+    out.extend(encode_pack_bcd(&record.version));
+    out.extend(encode_pack_bcd(&record.transmission_sn));
+
+    let mut stock_code = record.stock_code.clone();
+    while stock_code.len() < 6 {
+        stock_code.push(' ');
+    }
+    out.extend(stock_code.as_bytes());
+
+    out.extend(encode_pack_bcd(&record.matching_time));
+    out.push(record.disclosed_item_remarks);
+    out.push(record.rise_fall_remarks);
+    out.push(record.status_remarks);
+    out.extend(encode_pack_bcd(&format!("{:08}", record.accumulative_volume)));
+
+    for quote in &record.real_time_quotes {
+        out.extend(encode_pack_bcd(&quote.price));
+        out.extend(encode_pack_bcd(&quote.volume));
+    }
+
+    out.push(compute_checksum(&out));
+    out.extend(record.terminal_code);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a raw Format 6 record. `disclosed_item_remarks` drives how
+    /// many `quotes` (5-byte BCD price, 4-byte BCD volume) must be
+    /// supplied: its high nibble is the bid count, its low nibble the
+    /// ask count.
+    fn sample_record_bytes(disclosed_item_remarks: u8, quotes: &[([u8; 5], [u8; 4])]) -> Vec<u8> {
+        // Build raw_record exactly as in the spec example
+        let mut raw = Vec::new();
+        raw.push(0x1B); // ESC
+
+        // HEADER
+        raw.extend(&[0x00, 0x47]); // InfoLength = "0047"
+        raw.push(0x01);            // Business Type "01"
+        raw.push(0x06);            // Format Code "06"
+        raw.push(0x04);            // Version "04"
+        raw.extend(&[0x00,0x00,0x00,0x01]); // S/N "00000001"
+
+        // BODY
+        raw.extend(b"2330  ");             // StockCode
+        raw.extend(&[0x09,0x30,0x15,0x12,0x34,0x56]); // Matching Time
+        raw.push(disclosed_item_remarks); // Disclosed Item Remarks
+        raw.push(0x00); // Rise/Fall Remarks
+        raw.push(0x80); // Status Remarks
+        raw.extend(&[0x00,0x00,0x12,0x34]); // Accum Volume
+        for (price, volume) in quotes {
+            raw.extend(price);
+            raw.extend(volume);
+        }
+
+        raw.push(0x5A); // Checksum
+        raw.extend(&[0x0D,0x0A]); // Terminal Code
+        raw
+    }
+
+    #[test]
+    fn test_parse_format6() {
+        let raw = sample_record_bytes(0x10, &[([0x00,0x12,0x34,0x56,0x70], [0x00,0x00,0x01,0x00])]);
+        let rec = parse_format6(&raw).unwrap();
+
+        assert_eq!(rec.esc_code, 0x1B);
+        assert_eq!(rec.info_length, 47);
+        assert_eq!(&rec.business_type, "01");
+        assert_eq!(&rec.format_code,   "06");
+        assert_eq!(&rec.version,       "04");
+        assert_eq!(&rec.transmission_sn, "00000001");
+        assert_eq!(&rec.stock_code,    "2330");
+        assert_eq!(&rec.matching_time, "093015123456");
+        assert_eq!(rec.disclosed_item_remarks, 0x10);
+        assert_eq!(rec.rise_fall_remarks,      0x00);
+        assert_eq!(rec.status_remarks,         0x80);
+        assert_eq!(rec.accumulative_volume,    1234);
+        assert_eq!(rec.bid_count, 1);
+        assert_eq!(rec.ask_count, 0);
+        assert_eq!(rec.real_time_quotes.len(), 1);
+        assert_eq!(&rec.real_time_quotes[0].price,  "001234567");
+        assert_eq!(&rec.real_time_quotes[0].volume, "00000100");
+        assert_eq!(rec.checksum,  0x5A);
+        assert_eq!(rec.terminal_code, [0x0D,0x0A]);
+    }
+
+    #[test]
+    fn test_parse_format6_variable_bid_and_ask_quotes() {
+        let raw = sample_record_bytes(
+            0x12, // bid_count = 1, ask_count = 2
+            &[
+                ([0x00,0x12,0x34,0x56,0x70], [0x00,0x00,0x01,0x00]),
+                ([0x00,0x12,0x34,0x56,0x80], [0x00,0x00,0x02,0x00]),
+                ([0x00,0x12,0x34,0x56,0x90], [0x00,0x00,0x03,0x00]),
+            ],
+        );
+        let rec = parse_format6(&raw).unwrap();
+
+        assert_eq!(rec.bid_count, 1);
+        assert_eq!(rec.ask_count, 2);
+        assert_eq!(rec.real_time_quotes.len(), 3);
+        assert_eq!(&rec.real_time_quotes[0].volume, "00000100");
+        assert_eq!(&rec.real_time_quotes[1].volume, "00000200");
+        assert_eq!(&rec.real_time_quotes[2].volume, "00000300");
+        // Checksum and terminal code still land right after the last quote.
+        assert_eq!(rec.checksum, 0x5A);
+        assert_eq!(rec.terminal_code, [0x0D, 0x0A]);
+    }
+
+    #[test]
+    fn test_parse_format6_truncated_input_is_an_error() {
+        let raw = sample_record_bytes(0x10, &[([0x00,0x12,0x34,0x56,0x70], [0x00,0x00,0x01,0x00])]);
+        let err = parse_format6(&raw[..10]).unwrap_err();
+        assert!(matches!(err, ParseError::Truncated { .. }));
+    }
+
+    #[test]
+    fn test_parse_format6_invalid_bcd_nibble_is_an_error() {
+        let mut raw = sample_record_bytes(0x10, &[([0x00,0x12,0x34,0x56,0x70], [0x00,0x00,0x01,0x00])]);
+        raw[1] = 0xAF; // InfoLength high nibble is 0xA, not a digit
+        let err = parse_format6(&raw).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidBcdNibble { .. }));
+    }
+
+    #[test]
+    fn test_parse_format6_invalid_utf8_stock_code_is_an_error() {
+        let mut raw = sample_record_bytes(0x10, &[([0x00,0x12,0x34,0x56,0x70], [0x00,0x00,0x01,0x00])]);
+        raw[10] = 0xFF; // first byte of the stock code field
+        let err = parse_format6(&raw).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidUtf8 { .. }));
+    }
+
+    #[test]
+    fn test_compute_checksum_xors_esc_through_byte_before_checksum() {
+        assert_eq!(compute_checksum(&[0x1B, 0x01, 0x02]), 0x1B ^ 0x01 ^ 0x02);
+        assert_eq!(compute_checksum(&[]), 0x00);
+    }
+
+    #[test]
+    fn test_parse_format6_checked_accepts_a_correct_checksum() {
+        let mut raw = sample_record_bytes(0x10, &[([0x00,0x12,0x34,0x56,0x70], [0x00,0x00,0x01,0x00])]);
+        let checksum_offset = raw.len() - 3; // checksum precedes the 2-byte terminal
+        raw[checksum_offset] = compute_checksum(&raw[..checksum_offset]);
+
+        let rec = parse_format6_checked(&raw).unwrap();
+        assert_eq!(rec.checksum, raw[checksum_offset]);
+    }
+
+    #[test]
+    fn test_parse_format6_checked_rejects_a_wrong_checksum() {
+        let raw = sample_record_bytes(0x10, &[([0x00,0x12,0x34,0x56,0x70], [0x00,0x00,0x01,0x00])]);
+        let err = parse_format6_checked(&raw).unwrap_err();
+        assert!(matches!(err, ParseError::ChecksumMismatch { .. }));
+    }
+
+    #[test]
+    fn test_real_time_quote_price_and_volume_decimal() {
+        let quote = RealTimeQuote { price: "001234567".to_string(), volume: "00000100".to_string() };
+
+        assert_eq!(quote.price_decimal(EQUITY_PRICE_SCALE), Decimal::new(1234567, 3));
+        assert_eq!(quote.volume_decimal(), Decimal::new(100, 0));
+    }
+
+    #[test]
+    fn test_format6_record_quote_decimals_default_to_equity_scale() {
+        let raw = sample_record_bytes(
+            0x12,
+            &[
+                ([0x00,0x12,0x34,0x56,0x70], [0x00,0x00,0x01,0x00]),
+                ([0x00,0x12,0x34,0x56,0x80], [0x00,0x00,0x02,0x00]),
+                ([0x00,0x12,0x34,0x56,0x90], [0x00,0x00,0x03,0x00]),
+            ],
+        );
+        let rec = parse_format6(&raw).unwrap();
+        assert_eq!(rec.price_scale, EQUITY_PRICE_SCALE);
+
+        assert_eq!(
+            rec.quote_prices_decimal(),
+            vec![Decimal::new(1234567, 3), Decimal::new(1234568, 3), Decimal::new(1234569, 3)],
+        );
+        assert_eq!(
+            rec.quote_volumes_decimal(),
+            vec![Decimal::new(100, 0), Decimal::new(200, 0), Decimal::new(300, 0)],
+        );
+    }
+
+    #[test]
+    fn test_format6_record_quote_decimals_respect_overridden_price_scale() {
+        let raw = sample_record_bytes(0x10, &[([0x00,0x12,0x34,0x56,0x70], [0x00,0x00,0x01,0x00])]);
+        let mut rec = parse_format6(&raw).unwrap();
+        rec.price_scale = 0; // e.g. an index quoted without implied decimals
+
+        assert_eq!(rec.quote_prices_decimal(), vec![Decimal::new(1234567, 0)]);
+    }
+
+    #[test]
+    fn test_encode_format6_rejects_a_non_digit_bcd_field() {
+        let raw = sample_record_bytes(0x10, &[([0x00,0x12,0x34,0x56,0x70], [0x00,0x00,0x01,0x00])]);
+        let mut rec = parse_format6(&raw).unwrap();
+        rec.transmission_sn = "0000000X".to_string();
+
+        let err = encode_format6(&rec).unwrap_err();
+        assert_eq!(
+            err,
+            EncodeError::NonDigitField { field: "transmission_sn", found: 'X' },
+        );
+    }
+
+    #[test]
+    fn test_encode_format6_rejects_an_info_length_too_wide_for_its_bcd_field() {
+        let raw = sample_record_bytes(0x10, &[([0x00,0x12,0x34,0x56,0x70], [0x00,0x00,0x01,0x00])]);
+        let mut rec = parse_format6(&raw).unwrap();
+        rec.info_length = 10_000; // doesn't fit the 4-digit InfoLength field
+
+        let err = encode_format6(&rec).unwrap_err();
+        assert_eq!(
+            err,
+            EncodeError::FieldTooWide { field: "info_length", max_digits: 4, value: 10_000 },
+        );
+    }
+
+    #[test]
+    fn test_encode_format6_rejects_an_accumulative_volume_too_wide_for_its_bcd_field() {
+        let raw = sample_record_bytes(0x10, &[([0x00,0x12,0x34,0x56,0x70], [0x00,0x00,0x01,0x00])]);
+        let mut rec = parse_format6(&raw).unwrap();
+        rec.accumulative_volume = 100_000_000; // doesn't fit the 8-digit field
+
+        let err = encode_format6(&rec).unwrap_err();
+        assert_eq!(
+            err,
+            EncodeError::FieldTooWide {
+                field: "accumulative_volume",
+                max_digits: 8,
+                value: 100_000_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_encode_format6_round_trips_through_parse_format6() {
+        // The price field's trailing nibble is padding (9 digits is odd),
+        // so it must match what `encode_pack_bcd` itself pads with (0xF)
+        // for the re-encoded bytes, and hence the checksum, to come back
+        // byte-identical.
+        let mut raw = sample_record_bytes(
+            0x12,
+            &[
+                ([0x00,0x12,0x34,0x56,0x7F], [0x00,0x00,0x01,0x00]),
+                ([0x00,0x12,0x34,0x56,0x8F], [0x00,0x00,0x02,0x00]),
+                ([0x00,0x12,0x34,0x56,0x9F], [0x00,0x00,0x03,0x00]),
+            ],
+        );
+        let checksum_offset = raw.len() - 3;
+        raw[checksum_offset] = compute_checksum(&raw[..checksum_offset]);
+        let rec = parse_format6(&raw).unwrap();
+
+        let encoded = encode_format6(&rec).unwrap();
+        let round_tripped = parse_format6(&encoded).unwrap();
+
+        assert_eq!(round_tripped, rec);
+        // And the checksum we emitted should itself verify.
+        assert!(parse_format6_checked(&encoded).is_ok());
+    }
+}
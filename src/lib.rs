@@ -0,0 +1,17 @@
+// Run `cargo test` (add `--features simd` on nightly to exercise the
+// vectorized BCD codec).
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+mod bcd;
+mod combinators;
+mod decoder;
+mod error;
+mod format6;
+
+pub use bcd::{decode_pack_bcd, encode_pack_bcd};
+pub use decoder::Format6Decoder;
+pub use error::{EncodeError, ParseError};
+pub use format6::{
+    compute_checksum, encode_format6, parse_format6, parse_format6_checked, Format6Record,
+    RealTimeQuote, EQUITY_PRICE_SCALE,
+};